@@ -0,0 +1,109 @@
+//! File classification, human-readable sizing, and checksum fingerprinting
+//! for artifacts written by path-based commands (screenshot, pdf, download,
+//! trace, har, video, state).
+
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+/// Broad category inferred from a file's extension, used to annotate saved
+/// artifacts (e.g. `Downloaded to report.xlsx (excel, 842 KB, sha256:3f9a1c…)`).
+pub fn classify(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+        .as_str()
+    {
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" | "bmp" => "image",
+        "pdf" => "pdf",
+        "zip" | "tar" | "gz" | "7z" | "rar" => "archive",
+        "doc" | "docx" | "rtf" => "word",
+        "xls" | "xlsx" | "csv" => "excel",
+        "rs" | "js" | "ts" | "py" | "go" | "java" | "c" | "cpp" | "json" | "html" | "css" => {
+            "code"
+        }
+        "webm" | "mp4" | "mov" | "avi" | "mkv" => "video",
+        _ => "file",
+    }
+}
+
+/// Formats a byte count as a human-readable size (B/KB/MB/GB).
+pub fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{} {}", trim_trailing_zero(size), UNITS[unit])
+    }
+}
+
+/// Formats a size with one decimal place, dropping a trailing `.0`
+/// (`842.0` -> `842`, `2.1` stays `2.1`).
+fn trim_trailing_zero(value: f64) -> String {
+    let formatted = format!("{:.1}", value);
+    formatted
+        .strip_suffix(".0")
+        .map(str::to_string)
+        .unwrap_or(formatted)
+}
+
+/// Category, human-readable size, and (optionally) a short SHA-256 prefix
+/// for a file just written to disk.
+pub struct ArtifactInfo {
+    pub category: &'static str,
+    pub size: String,
+    pub sha256_prefix: Option<String>,
+}
+
+impl ArtifactInfo {
+    /// Reads `path`'s metadata to compute its size and, unless
+    /// `skip_checksum` is set, streams its bytes through SHA-256 for a short
+    /// checksum prefix. Skipping the checksum avoids reading large files
+    /// (e.g. multi-GB videos) into memory at all. Returns `None` if the file
+    /// can't be read (e.g. it no longer exists).
+    pub fn read(path: &Path, skip_checksum: bool) -> Option<Self> {
+        let size = fs::metadata(path).ok()?.len();
+        let sha256_prefix = if skip_checksum {
+            None
+        } else {
+            let mut file = File::open(path).ok()?;
+            let mut hasher = Sha256::new();
+            let mut buf = [0u8; 64 * 1024];
+            loop {
+                let read = file.read(&mut buf).ok()?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buf[..read]);
+            }
+            Some(
+                hasher.finalize()[..3]
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect(),
+            )
+        };
+        Some(Self {
+            category: classify(path),
+            size: human_size(size),
+            sha256_prefix,
+        })
+    }
+
+    /// Renders as a trailing annotation, e.g. ` (excel, 842 KB, sha256:3f9a1c…)`.
+    pub fn annotation(&self) -> String {
+        match &self.sha256_prefix {
+            Some(prefix) => format!(" ({}, {}, sha256:{}…)", self.category, self.size, prefix),
+            None => format!(" ({}, {})", self.category, self.size),
+        }
+    }
+}
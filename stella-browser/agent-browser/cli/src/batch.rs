@@ -0,0 +1,135 @@
+//! Batch/pipeline execution: runs a list of commands read from stdin or a
+//! script file against a single session. Consecutive read-only steps
+//! (snapshot, get, screenshot, …) run concurrently, bounded by a
+//! semaphore, while mutating steps run one at a time and act as a barrier
+//! between read-only batches.
+
+use crate::connection::Response;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Commands that only read page/browser state and are therefore safe to
+/// run concurrently with each other; anything else is treated as mutating
+/// and runs strictly in submission order. `eval` runs arbitrary page
+/// JavaScript (can mutate DOM/session state) and is excluded even though it
+/// can be used for reads.
+const READ_ONLY_COMMANDS: &[&str] = &["snapshot", "get", "is", "screenshot", "console", "errors"];
+
+/// `tab`/`tab list` (no args, or an explicit `list`) only reads the tab
+/// list; `tab new`, `tab close`, and `tab <index>` all mutate the window,
+/// so only the listing form is read-only.
+fn is_read_only_tab(args: &[String]) -> bool {
+    args.is_empty() || args[0] == "list"
+}
+
+pub fn is_read_only(command: &str, args: &[String]) -> bool {
+    if command == "tab" {
+        return is_read_only_tab(args);
+    }
+    READ_ONLY_COMMANDS.contains(&command)
+}
+
+/// One parsed line of a batch script/stdin stream.
+#[derive(Clone)]
+pub struct BatchStep {
+    pub index: usize,
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+/// Parses newline- or JSON-delimited batch input into steps. A line
+/// starting with `[` is parsed as a JSON argv array (`["click", "#submit"]`);
+/// anything else is split on whitespace. Blank lines and `#`-comments are
+/// skipped.
+pub fn parse_steps(input: &str) -> Vec<BatchStep> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .enumerate()
+        .map(|(index, line)| {
+            let mut args: Vec<String> = if line.starts_with('[') {
+                serde_json::from_str(line).unwrap_or_default()
+            } else {
+                line.split_whitespace().map(str::to_string).collect()
+            };
+            let command = if args.is_empty() {
+                String::new()
+            } else {
+                args.remove(0)
+            };
+            BatchStep {
+                index,
+                command,
+                args,
+            }
+        })
+        .collect()
+}
+
+/// Outcome of running one [`BatchStep`].
+pub struct BatchResult {
+    pub step: BatchStep,
+    pub response: Response,
+}
+
+/// A command executor, boxed so [`run_batch`] doesn't need to know how
+/// steps are actually dispatched (CDP calls, IPC to the browser process, …).
+pub type Executor = Arc<
+    dyn Fn(String, Vec<String>) -> Pin<Box<dyn Future<Output = Response> + Send>> + Send + Sync,
+>;
+
+/// Runs `steps` through `execute`, batching consecutive read-only steps to
+/// run in parallel (bounded by `concurrency`) while each mutating step runs
+/// alone. Results come back in original step order regardless of how they
+/// were scheduled.
+pub async fn run_batch(
+    steps: Vec<BatchStep>,
+    concurrency: usize,
+    execute: Executor,
+) -> Vec<BatchResult> {
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut results = Vec::with_capacity(steps.len());
+    let mut group: Vec<BatchStep> = Vec::new();
+
+    for step in steps {
+        if is_read_only(&step.command, &step.args) {
+            group.push(step);
+            continue;
+        }
+        run_group(&mut group, &semaphore, &execute, &mut results).await;
+        let response = execute(step.command.clone(), step.args.clone()).await;
+        results.push(BatchResult { step, response });
+    }
+    run_group(&mut group, &semaphore, &execute, &mut results).await;
+    results
+}
+
+async fn run_group(
+    group: &mut Vec<BatchStep>,
+    semaphore: &Arc<Semaphore>,
+    execute: &Executor,
+    results: &mut Vec<BatchResult>,
+) {
+    if group.is_empty() {
+        return;
+    }
+    let mut handles = Vec::new();
+    for step in group.drain(..) {
+        let semaphore = semaphore.clone();
+        let execute = execute.clone();
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await.unwrap();
+            let response = execute(step.command.clone(), step.args.clone()).await;
+            BatchResult { step, response }
+        }));
+    }
+    for handle in handles {
+        if let Ok(result) = handle.await {
+            results.push(result);
+        }
+    }
+    results.sort_by_key(|r| r.step.index);
+}
@@ -0,0 +1,115 @@
+//! W3C-capability-style browser knobs configurable via `set`, so automation
+//! users can change proxy, load strategy, TLS, and timeout behavior without
+//! relaunching the browser.
+
+/// `set proxy <server> [--bypass <list>]` — routes the context through an
+/// HTTP/SOCKS proxy, mirroring the W3C `proxy` capability.
+#[derive(Clone, Debug)]
+pub struct ProxyConfig {
+    pub server: String,
+    pub bypass: Vec<String>,
+}
+
+/// `set pageload <strategy>` — the default load strategy used by
+/// `open`/`goto`, mirroring the W3C `pageLoadStrategy` capability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageLoadStrategy {
+    /// Wait for the `load` event (Playwright's default).
+    Normal,
+    /// Wait only for `domcontentloaded`.
+    Eager,
+    /// Don't wait at all; `open` returns as soon as navigation starts.
+    None,
+}
+
+impl PageLoadStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "load" | "normal" => Some(Self::Normal),
+            "domcontentloaded" | "eager" => Some(Self::Eager),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// `set timeouts --navigation <ms> --action <ms>` — default waits applied
+/// to every subsequent command, mirroring the W3C `timeouts` capability.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timeouts {
+    pub navigation_ms: Option<u64>,
+    pub action_ms: Option<u64>,
+}
+
+/// `set dialog <policy> [text]` — a persistent default handler for
+/// alert/confirm/prompt dialogs, mirroring the W3C
+/// `unhandledPromptBehavior` capability. `Manual` restores the default
+/// wait-for-explicit-`dialog`-invocation behavior.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialogPolicy {
+    Accept,
+    Dismiss,
+    AcceptNotify,
+    DismissNotify,
+    Manual,
+}
+
+impl DialogPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "accept" => Some(Self::Accept),
+            "dismiss" => Some(Self::Dismiss),
+            "accept-notify" => Some(Self::AcceptNotify),
+            "dismiss-notify" => Some(Self::DismissNotify),
+            "manual" => Some(Self::Manual),
+            _ => None,
+        }
+    }
+
+    /// Whether dialogs under this policy should still surface a console
+    /// notification (the W3C `*-notify` variants).
+    pub fn notifies(&self) -> bool {
+        matches!(self, Self::AcceptNotify | Self::DismissNotify)
+    }
+}
+
+/// `set insecure <on|off>` — accept invalid TLS certificates for the
+/// remainder of the session, mirroring the W3C `acceptInsecureCerts`
+/// capability.
+pub fn parse_accept_insecure_certs(value: &str) -> Option<bool> {
+    match value {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// A recognized Chrome desktop User-Agent string, used by
+/// `set useragent chrome` as a quick preset.
+pub const CHROME_USER_AGENT_PRESET: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36";
+
+/// Resource-loading and fingerprint-tuning knobs exposed via `set`,
+/// mirroring the crawler-tuning surface of headless-driver tools like
+/// sparkledriver.
+#[derive(Clone, Debug, Default)]
+pub struct ResourceOptions {
+    /// `set useragent <string>`, with `chrome` resolving to
+    /// [`CHROME_USER_AGENT_PRESET`].
+    pub user_agent: Option<String>,
+    /// `set cache on|off` — toggles the HTTP cache.
+    pub cache_enabled: Option<bool>,
+    /// `set ajax-idle <ms>` — how long `wait --load networkidle` tolerates
+    /// in-flight XHR/fetch requests before considering the page settled.
+    pub ajax_idle_ms: Option<u64>,
+    /// `set save-media <dir>` — persist media/attachment responses here.
+    pub save_media_dir: Option<String>,
+}
+
+/// Resolves a `set useragent` value, expanding the `chrome` preset.
+pub fn resolve_user_agent(value: &str) -> String {
+    if value.eq_ignore_ascii_case("chrome") {
+        CHROME_USER_AGENT_PRESET.to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,48 @@
+//! NATIVE_APP/WEBVIEW context switching for iOS Safari automation, so an
+//! agent can drop into the native context to dismiss system dialogs,
+//! fraud warnings, or native keyboard/file pickers, then return to the DOM.
+
+/// One context reported by the WDA session's `/contexts` endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Context {
+    Native,
+    WebView(String),
+}
+
+impl Context {
+    /// Parses a raw WDA context name (`"NATIVE_APP"` or `"WEBVIEW_12345"`).
+    pub fn parse(raw: &str) -> Self {
+        if raw == "NATIVE_APP" {
+            Self::Native
+        } else {
+            Self::WebView(raw.to_string())
+        }
+    }
+
+    /// The raw WDA context name this variant corresponds to.
+    pub fn wda_name(&self) -> String {
+        match self {
+            Self::Native => "NATIVE_APP".to_string(),
+            Self::WebView(id) => id.clone(),
+        }
+    }
+}
+
+/// Resolves a `context <name>` argument, including the `web`/`native`
+/// convenience aliases, against the contexts the session actually
+/// reports. Returns an error naming the available contexts if nothing matches.
+pub fn resolve<'a>(requested: &str, available: &'a [Context]) -> Result<&'a Context, String> {
+    let matches = |ctx: &Context| match requested {
+        "native" => matches!(ctx, Context::Native),
+        "web" => matches!(ctx, Context::WebView(_)),
+        name => ctx.wda_name() == name,
+    };
+    available.iter().find(|c| matches(c)).ok_or_else(|| {
+        let names: Vec<String> = available.iter().map(Context::wda_name).collect();
+        format!(
+            "no such context {:?}; available contexts: {}",
+            requested,
+            names.join(", ")
+        )
+    })
+}
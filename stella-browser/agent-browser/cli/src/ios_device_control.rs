@@ -0,0 +1,56 @@
+//! XCUITest device controls exposed via `device` subcommands: lock/unlock,
+//! backgrounding, clipboard, and orientation. Each maps to the
+//! corresponding WDA/XCUITest session endpoint and requires the `ios`
+//! provider.
+
+/// WDA endpoint paths for each device control, relative to the session
+/// root (`/session/:id/...`).
+pub mod endpoints {
+    pub const LOCK: &str = "wda/lock";
+    pub const UNLOCK: &str = "wda/unlock";
+    pub const IS_LOCKED: &str = "wda/locked";
+    pub const HOME: &str = "wda/homescreen";
+    pub const SET_PASTEBOARD: &str = "wda/setPasteboard";
+    pub const GET_PASTEBOARD: &str = "wda/getPasteboard";
+    pub const ORIENTATION: &str = "orientation";
+}
+
+/// `device orientation <portrait|landscape>`.
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+impl Orientation {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "portrait" => Some(Self::Portrait),
+            "landscape" => Some(Self::Landscape),
+            _ => None,
+        }
+    }
+
+    /// The WDA `/session/:id/orientation` payload value.
+    pub fn wda_value(&self) -> &'static str {
+        match self {
+            Self::Portrait => "PORTRAIT",
+            Self::Landscape => "LANDSCAPE",
+        }
+    }
+}
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+
+/// Base64-encodes `text` for `wda/setPasteboard`, which expects base64
+/// content regardless of pasteboard type.
+pub fn encode_clipboard(text: &str) -> String {
+    BASE64.encode(text.as_bytes())
+}
+
+/// Decodes a `wda/getPasteboard` response body back into plain text.
+pub fn decode_clipboard(encoded: &str) -> Result<String, String> {
+    let bytes = BASE64
+        .decode(encoded)
+        .map_err(|e| format!("invalid base64 in pasteboard content: {e}"))?;
+    String::from_utf8(bytes).map_err(|e| format!("pasteboard content was not valid UTF-8: {e}"))
+}
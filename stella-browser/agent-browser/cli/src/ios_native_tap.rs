@@ -0,0 +1,52 @@
+//! nativeWebTap fallback for unreliable iOS Safari element taps: converts a
+//! CSS-pixel bounding box into native XCUITest screen coordinates and
+//! issues a touch through the WDA touch-action API, giving semantic `tap`
+//! the reliability of Appium's `nativeWebTap`.
+
+/// A CSS-pixel bounding box, as reported by `get box <selector>`.
+pub struct CssBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// The current page's viewport transform: `visualViewport` scale/offset
+/// plus the device's scale factor.
+pub struct ViewportTransform {
+    pub visual_viewport_scale: f64,
+    pub visual_viewport_offset_x: f64,
+    pub visual_viewport_offset_y: f64,
+    pub device_scale_factor: f64,
+}
+
+/// A point in native screen coordinates, ready for the XCUITest
+/// touch-action API.
+pub struct ScreenPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Converts `css_box`'s center into native screen coordinates by applying
+/// `transform`'s visualViewport scale/offset and device scale factor.
+pub fn css_box_center_to_screen_point(css_box: &CssBox, transform: &ViewportTransform) -> ScreenPoint {
+    let center_x = css_box.x + css_box.width / 2.0;
+    let center_y = css_box.y + css_box.height / 2.0;
+
+    let viewport_x = (center_x - transform.visual_viewport_offset_x) * transform.visual_viewport_scale;
+    let viewport_y = (center_y - transform.visual_viewport_offset_y) * transform.visual_viewport_scale;
+
+    ScreenPoint {
+        x: viewport_x * transform.device_scale_factor,
+        y: viewport_y * transform.device_scale_factor,
+    }
+}
+
+/// Whether native tap mode should be used: an explicit `--native` flag, or
+/// the session-level `STELLA_BROWSER_IOS_NATIVE_TAP` toggle.
+pub fn native_tap_requested(explicit_flag: bool) -> bool {
+    explicit_flag
+        || std::env::var("STELLA_BROWSER_IOS_NATIVE_TAP")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+}
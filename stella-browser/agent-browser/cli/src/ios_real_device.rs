@@ -0,0 +1,184 @@
+//! Real iOS device support via `go-ios` (danielpaulus/go-ios) + WebDriverAgent,
+//! used by `stella-browser -p ios --udid <udid>` to drive a physical iPhone
+//! over USB instead of a simulator.
+
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::process::Command as AsyncCommand;
+use tokio::time::{sleep, timeout, Instant};
+
+/// One physical device reported by `ios list --details`.
+pub struct RealDevice {
+    pub udid: String,
+    pub name: String,
+    pub product_version: String,
+}
+
+/// Runs `ios list --details` and parses its JSON into [`RealDevice`]s.
+/// Used by `device list --real` alongside `simctl list`.
+pub fn list_real_devices() -> Result<Vec<RealDevice>, String> {
+    let output = Command::new("ios")
+        .args(["list", "--details"])
+        .output()
+        .map_err(|e| format!("failed to run `ios list`, is go-ios installed? ({e})"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "`ios list` exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("failed to parse `ios list` output: {e}"))?;
+    let devices = parsed
+        .get("deviceList")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    Ok(devices
+        .iter()
+        .filter_map(|d| {
+            Some(RealDevice {
+                udid: d.get("Udid").and_then(|v| v.as_str())?.to_string(),
+                name: d
+                    .get("DeviceName")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("iPhone")
+                    .to_string(),
+                product_version: d
+                    .get("ProductVersion")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+            })
+        })
+        .collect())
+}
+
+/// How long to wait for `ios tunnel start` to report the tunnel as active,
+/// and for `ios runwda`'s WDA listener to start accepting connections.
+/// Both commands are long-running daemons that never exit on success, so
+/// success is observed by polling rather than by waiting on the child.
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Ensures the iOS 17+ tunnel daemon is running for `udid`, starting it via
+/// `ios tunnel start` if necessary. Requires elevated privileges, and on
+/// Windows requires `wintun.dll` in `system32`.
+///
+/// `ios tunnel start` stays resident for the lifetime of the tunnel rather
+/// than exiting, so it's spawned detached and its readiness is polled via
+/// `ios tunnel ls` instead of waiting for it to exit.
+pub async fn ensure_tunnel(udid: &str) -> Result<(), String> {
+    if tunnel_is_active(udid).await? {
+        return Ok(());
+    }
+
+    AsyncCommand::new("ios")
+        .args(["tunnel", "start", "--udid", udid])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("failed to spawn `ios tunnel start`: {e}"))?;
+
+    let deadline = Instant::now() + READY_TIMEOUT;
+    loop {
+        if tunnel_is_active(udid).await? {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "`ios tunnel start` did not become active for {udid} within {READY_TIMEOUT:?}. \
+                 This requires elevated privileges, and on Windows requires wintun.dll in system32."
+            ));
+        }
+        sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Checks `ios tunnel ls` for an active tunnel to `udid`.
+async fn tunnel_is_active(udid: &str) -> Result<bool, String> {
+    let output = AsyncCommand::new("ios")
+        .args(["tunnel", "ls"])
+        .output()
+        .await
+        .map_err(|e| format!("failed to run `ios tunnel ls`: {e}"))?;
+    Ok(output.status.success() && String::from_utf8_lossy(&output.stdout).contains(udid))
+}
+
+/// Installs (if needed) and launches WebDriverAgent on `udid` via
+/// `ios runwda`, returning the WDA listener port once it's actually
+/// accepting connections.
+///
+/// `ios runwda` stays resident serving WDA over HTTP rather than exiting,
+/// so it's spawned detached: its listener port is read off its stdout as it
+/// starts up, then the port is polled until WDA is actually reachable.
+pub async fn launch_webdriveragent(udid: &str) -> Result<u16, String> {
+    let mut child = AsyncCommand::new("ios")
+        .args(["runwda", "--udid", udid])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("failed to spawn `ios runwda`: {e}"))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "failed to capture `ios runwda` stdout".to_string())?;
+    let mut lines = BufReader::new(stdout).lines();
+
+    let port = timeout(READY_TIMEOUT, async {
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Some(port) = line
+                .rsplit(':')
+                .next()
+                .and_then(|s| s.trim().parse::<u16>().ok())
+            {
+                return Some(port);
+            }
+        }
+        None
+    })
+    .await
+    .map_err(|_| format!("timed out waiting for `ios runwda` to report a WDA listener port for {udid}"))?
+    .ok_or_else(|| {
+        format!("`ios runwda` exited before reporting a WDA listener port for {udid}")
+    })?;
+
+    wait_for_wda_port(port).await?;
+    Ok(port)
+}
+
+/// Polls `port` on localhost until WDA accepts a TCP connection or
+/// [`READY_TIMEOUT`] elapses.
+async fn wait_for_wda_port(port: u16) -> Result<(), String> {
+    let deadline = Instant::now() + READY_TIMEOUT;
+    loop {
+        if TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "WDA did not start accepting connections on port {port} within {READY_TIMEOUT:?}"
+            ));
+        }
+        sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Resolves which UDID to drive: an explicit `--udid` flag wins, falling
+/// back to `STELLA_BROWSER_IOS_UDID`. Returns a clear error (rather than
+/// silently picking a device) when neither is set.
+pub fn resolve_udid(explicit: Option<&str>) -> Result<String, String> {
+    explicit
+        .map(str::to_string)
+        .or_else(|| std::env::var("STELLA_BROWSER_IOS_UDID").ok())
+        .ok_or_else(|| {
+            "no iOS device selected: pass --udid <udid> or set STELLA_BROWSER_IOS_UDID".to_string()
+        })
+}
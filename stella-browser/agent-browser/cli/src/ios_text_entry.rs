@@ -0,0 +1,57 @@
+//! Keyboard-aware text entry for iOS: verifies the software keyboard is
+//! present before sending keystrokes, types through the XCUITest
+//! `sendKeys`/typeText endpoint instead of a JS value-set (which bypasses
+//! input events some pages depend on), and can dismiss the keyboard or
+//! clear a field natively afterward.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// WDA endpoint paths used for keyboard-aware input, relative to the
+/// session root (`/session/:id/...`).
+pub mod endpoints {
+    pub const KEYBOARD: &str = "wda/keyboard";
+    pub const SEND_KEYS: &str = "wda/keys";
+    pub const DISMISS_KEYBOARD: &str = "wda/keyboard/dismiss";
+}
+
+/// How long to poll for the software keyboard to appear before giving up
+/// and falling back to a native tap to (re)focus the field.
+pub const KEYBOARD_POLL_TIMEOUT: Duration = Duration::from_secs(3);
+const KEYBOARD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Polls `is_keyboard_present` until it reports the keyboard is visible or
+/// [`KEYBOARD_POLL_TIMEOUT`] elapses. Returns `true` once the keyboard is
+/// confirmed present. Uses `tokio::time::sleep` between polls so it never
+/// blocks the async executor thread it runs on.
+pub async fn wait_for_keyboard<F, Fut>(mut is_keyboard_present: F) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = Instant::now() + KEYBOARD_POLL_TIMEOUT;
+    loop {
+        if is_keyboard_present().await {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(KEYBOARD_POLL_INTERVAL).await;
+    }
+}
+
+/// Options accepted by the iOS input path for `type`/`fill`.
+#[derive(Default, Clone, Copy)]
+pub struct IosInputOptions {
+    /// `fill --clear-native`: select-all and delete through the native
+    /// keyboard instead of setting `.value`, since value-set bypasses
+    /// input events that some pages depend on.
+    pub clear_native: bool,
+    /// `type`/`fill --dismiss-keyboard`: tap "Done"/return after sending text.
+    pub dismiss_keyboard: bool,
+}
+
+/// The "Done"/return key labels XCUITest looks for when dismissing the
+/// keyboard, checked in order.
+pub const DISMISS_KEY_LABELS: &[&str] = &["Done", "Return", "Go", "Search"];
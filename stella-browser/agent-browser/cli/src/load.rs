@@ -0,0 +1,133 @@
+//! Parallel load-test mode (`stella-browser load <plan.yaml> --clients N
+//! --duration s`): replays a testplan's step list across N concurrent
+//! sessions until the duration elapses, collecting latency/outcome metrics.
+
+use crate::batch::Executor;
+use crate::testplan::TestPlan;
+use std::time::{Duration, Instant};
+
+/// Per-request outcome recorded during a load run.
+struct RequestSample {
+    latency_ms: u64,
+    failed: bool,
+}
+
+/// Aggregate statistics for a completed load run, matching stella's
+/// load-engine report shape (total requests, failures, latency percentiles).
+#[derive(serde::Serialize)]
+pub struct LoadReport {
+    pub clients: usize,
+    pub duration_s: u64,
+    pub total_requests: u64,
+    pub failures: u64,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+}
+
+pub struct LoadOptions {
+    pub clients: usize,
+    pub duration: Duration,
+    pub ramp: Duration,
+}
+
+/// Runs `plan` repeatedly across `options.clients` concurrent sessions
+/// until `options.duration` elapses, staggering client startup over
+/// `options.ramp` so sessions don't all hit the target at once.
+/// `new_session_executor` builds an isolated [`Executor`] per client
+/// (backed by the `session` subsystem).
+///
+/// Requests with a response status >= 400 count as failures unless the
+/// step carries its own `assert:` block that explicitly tolerates the
+/// response (i.e. the assertions all pass), matching stella's load-engine
+/// semantics where failures and timeouts both count toward the failure
+/// total.
+pub async fn run_load(
+    plan: TestPlan,
+    options: LoadOptions,
+    new_session_executor: impl Fn(usize) -> Executor,
+) -> LoadReport {
+    let deadline = Instant::now() + options.duration;
+    let ramp_step = if options.clients > 0 {
+        options.ramp / options.clients as u32
+    } else {
+        Duration::ZERO
+    };
+
+    let mut handles = Vec::with_capacity(options.clients);
+    for client_id in 0..options.clients {
+        let plan = plan.clone();
+        let execute = new_session_executor(client_id);
+        let start_delay = ramp_step * client_id as u32;
+        handles.push(tokio::spawn(async move {
+            tokio::time::sleep(start_delay).await;
+            run_client(plan, execute, deadline).await
+        }));
+    }
+
+    let mut samples = Vec::new();
+    for handle in handles {
+        if let Ok(mut client_samples) = handle.await {
+            samples.append(&mut client_samples);
+        }
+    }
+
+    summarize(options.clients, options.duration, samples)
+}
+
+async fn run_client(plan: TestPlan, execute: Executor, deadline: Instant) -> Vec<RequestSample> {
+    let mut samples = Vec::new();
+    'outer: loop {
+        if Instant::now() >= deadline {
+            break 'outer;
+        }
+        for step in &plan.steps {
+            if Instant::now() >= deadline {
+                break 'outer;
+            }
+            let started = Instant::now();
+            let response = execute(step.command.clone(), step.args.clone()).await;
+            let latency_ms = started.elapsed().as_millis() as u64;
+
+            let failed = if !response.success {
+                true
+            } else if let Some(assert) = &step.assert {
+                assert.check(&response).is_some()
+            } else {
+                let status = response
+                    .data
+                    .as_ref()
+                    .and_then(|d| d.get("status"))
+                    .and_then(|v| v.as_i64());
+                status.map(|s| s >= 400).unwrap_or(false)
+            };
+
+            samples.push(RequestSample { latency_ms, failed });
+        }
+    }
+    samples
+}
+
+fn summarize(clients: usize, duration: Duration, mut samples: Vec<RequestSample>) -> LoadReport {
+    let total_requests = samples.len() as u64;
+    let failures = samples.iter().filter(|s| s.failed).count() as u64;
+    samples.sort_by_key(|s| s.latency_ms);
+
+    let percentile = |p: f64| -> u64 {
+        if samples.is_empty() {
+            return 0;
+        }
+        let idx = ((samples.len() as f64 - 1.0) * p).round() as usize;
+        samples[idx].latency_ms
+    };
+
+    LoadReport {
+        clients,
+        duration_s: duration.as_secs(),
+        total_requests,
+        failures,
+        p50_ms: percentile(0.50),
+        p90_ms: percentile(0.90),
+        p99_ms: percentile(0.99),
+    }
+}
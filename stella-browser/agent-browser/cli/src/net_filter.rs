@@ -0,0 +1,111 @@
+//! Gitignore-style URL filtering for `network requests` output.
+//!
+//! Compiles repeated `--url-match`/`--url-ignore` glob patterns (plus
+//! `--resource-type` filters) into a [`RequestFilter`] that decides whether
+//! a captured request is worth printing.
+
+/// Compiled include/exclude matcher for the `network requests` branch of
+/// `print_response`. An empty match list means "match everything"; ignore
+/// patterns always take precedence over match patterns.
+pub struct RequestFilter {
+    matches: Vec<Glob>,
+    ignores: Vec<Glob>,
+    resource_types: Vec<String>,
+}
+
+impl RequestFilter {
+    pub fn new(url_match: &[String], url_ignore: &[String], resource_type: &[String]) -> Self {
+        Self {
+            matches: url_match.iter().map(|p| Glob::compile(p)).collect(),
+            ignores: url_ignore.iter().map(|p| Glob::compile(p)).collect(),
+            resource_types: resource_type.iter().map(|s| s.to_lowercase()).collect(),
+        }
+    }
+
+    /// Returns true if `url`/`resource_type` survive the configured filters.
+    pub fn allows(&self, url: &str, resource_type: &str) -> bool {
+        if !self.resource_types.is_empty()
+            && !self
+                .resource_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(resource_type))
+        {
+            return false;
+        }
+        if self.ignores.iter().any(|g| g.is_match(url)) {
+            return false;
+        }
+        if !self.matches.is_empty() && !self.matches.iter().any(|g| g.is_match(url)) {
+            return false;
+        }
+        true
+    }
+}
+
+/// A single compiled glob, expanded from brace alternation (`{a,b,c}`) into
+/// one or more literal patterns matched with `*`/`**` wildcard semantics.
+struct Glob {
+    patterns: Vec<String>,
+}
+
+impl Glob {
+    fn compile(pattern: &str) -> Self {
+        Self {
+            patterns: expand_braces(pattern),
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        self.patterns.iter().any(|p| glob_match(p, text))
+    }
+}
+
+/// Expands a single `{a,b,c}` brace group into its cross-product of
+/// patterns. Only one level of braces is supported, which covers the
+/// `**/*.{png,css,woff2}` style patterns this filter targets.
+fn expand_braces(pattern: &str) -> Vec<String> {
+    if let Some(start) = pattern.find('{') {
+        if let Some(end) = pattern[start..].find('}').map(|i| i + start) {
+            let prefix = &pattern[..start];
+            let suffix = &pattern[end + 1..];
+            let mut out = Vec::new();
+            for option in pattern[start + 1..end].split(',') {
+                for rest in expand_braces(suffix) {
+                    out.push(format!("{prefix}{option}{rest}"));
+                }
+            }
+            return out;
+        }
+    }
+    vec![pattern.to_string()]
+}
+
+/// Matches `text` against `pattern`, where `*` matches any run of
+/// characters except `/` and `**` matches any run of characters including
+/// `/` (so `**` can span path/host segments, matching gitignore semantics).
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    match_from(&p, &t)
+}
+
+fn match_from(p: &[char], t: &[char]) -> bool {
+    if p.is_empty() {
+        return t.is_empty();
+    }
+    if p[0] == '*' {
+        if p.len() > 1 && p[1] == '*' {
+            let rest = &p[2..];
+            (0..=t.len()).any(|i| match_from(rest, &t[i..]))
+        } else {
+            let rest = &p[1..];
+            (0..=t.len())
+                .take_while(|&i| i == 0 || t[i - 1] != '/')
+                .any(|i| match_from(rest, &t[i..]))
+        }
+    } else if p[0] == '?' {
+        !t.is_empty() && t[0] != '/' && match_from(&p[1..], &t[1..])
+    } else {
+        !t.is_empty() && t[0] == p[0] && match_from(&p[1..], &t[1..])
+    }
+}
@@ -1,7 +1,251 @@
+use crate::artifact::ArtifactInfo;
 use crate::color;
 use crate::connection::Response;
+use crate::net_filter::RequestFilter;
+use std::path::Path;
+
+/// Serializes a single cookie JSON object into one Netscape cookies.txt line:
+/// `domain\tincludeSubdomains\tpath\tsecure\texpires\tname\tvalue`.
+fn format_netscape_cookie_line(cookie: &serde_json::Value) -> String {
+    let domain = cookie.get("domain").and_then(|v| v.as_str()).unwrap_or("");
+    let include_subdomains = if domain.starts_with('.') { "TRUE" } else { "FALSE" };
+    let path = cookie.get("path").and_then(|v| v.as_str()).unwrap_or("/");
+    let secure = if cookie.get("secure").and_then(|v| v.as_bool()).unwrap_or(false) {
+        "TRUE"
+    } else {
+        "FALSE"
+    };
+    let expires = cookie
+        .get("expires")
+        .or_else(|| cookie.get("expirationDate"))
+        .and_then(|v| v.as_f64())
+        .filter(|e| *e > 0.0)
+        .map(|e| e as i64)
+        .unwrap_or(0);
+    let name = cookie.get("name").and_then(|v| v.as_str()).unwrap_or("");
+    let value = cookie.get("value").and_then(|v| v.as_str()).unwrap_or("");
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        domain, include_subdomains, path, secure, expires, name, value
+    )
+}
+
+/// Optional rendering knobs for [`print_response_opts`]. Defaults reproduce
+/// the plain, unfiltered output of [`print_response`].
+#[derive(Default)]
+pub struct PrintOptions<'a> {
+    /// Render the `cookies` branch as `Some("netscape")` (cookies.txt) or
+    /// `Some("json")` instead of `name=value` lines.
+    pub cookie_format: Option<&'a str>,
+    /// Restrict the `requests` branch to entries the filter allows.
+    pub request_filter: Option<&'a RequestFilter>,
+    /// Skip hashing written artifacts (useful for large videos).
+    pub no_checksum: bool,
+    /// Print a dim `⏱ 1.24s · 37 requests · 2.1 MB` footer sourced from
+    /// `data.stats` after the normal output.
+    pub show_stats: bool,
+}
+
+/// Prints the `⏱ <duration> · <count> requests · <size>` stats footer
+/// described by a `stats` object (`durationMs`, `requestCount`, `bytesRead`).
+/// Request count and bytes are omitted when the response carries no
+/// network/navigation data.
+fn print_stats_footer(stats: &serde_json::Value) {
+    let mut parts = Vec::new();
+    if let Some(ms) = stats.get("durationMs").and_then(|v| v.as_f64()) {
+        parts.push(format!("{:.2}s", ms / 1000.0));
+    }
+    if let Some(count) = stats.get("requestCount").and_then(|v| v.as_i64()) {
+        parts.push(format!("{} requests", count));
+    }
+    if let Some(bytes) = stats.get("bytesRead").and_then(|v| v.as_u64()) {
+        parts.push(crate::artifact::human_size(bytes));
+    }
+    if !parts.is_empty() {
+        println!("{}", color::dim(&format!("⏱ {}", parts.join(" · "))));
+    }
+}
+
+/// Recursively prints one accessibility node as `role "name" [flags]`,
+/// indented two spaces per depth level, e.g. `checkbox "Accept terms" [checked]`.
+fn print_accessibility_node(node: &serde_json::Value, depth: usize) {
+    let role = node.get("role").and_then(|v| v.as_str()).unwrap_or("generic");
+    let name = node.get("name").and_then(|v| v.as_str()).unwrap_or("");
+
+    let mut flags = Vec::new();
+    for (key, label) in [
+        ("focused", "focused"),
+        ("checked", "checked"),
+        ("expanded", "expanded"),
+        ("disabled", "disabled"),
+    ] {
+        if node.get(key).and_then(|v| v.as_bool()).unwrap_or(false) {
+            flags.push(label);
+        }
+    }
+
+    let indent = "  ".repeat(depth);
+    match (name.is_empty(), flags.is_empty()) {
+        (true, true) => println!("{}{}", indent, role),
+        (true, false) => println!("{}{} [{}]", indent, role, flags.join(", ")),
+        (false, true) => println!("{}{} \"{}\"", indent, role, name),
+        (false, false) => println!("{}{} \"{}\" [{}]", indent, role, name, flags.join(", ")),
+    }
+
+    if let Some(children) = node.get("children").and_then(|v| v.as_array()) {
+        for child in children {
+            print_accessibility_node(child, depth + 1);
+        }
+    }
+}
+
+/// Reads the file just written to `path` and renders a trailing
+/// ` (category, size, sha256:prefix…)` annotation, or an empty string if
+/// the file can't be read.
+fn artifact_annotation(path: &str, no_checksum: bool) -> String {
+    ArtifactInfo::read(Path::new(path), no_checksum)
+        .map(|info| info.annotation())
+        .unwrap_or_default()
+}
+
+/// Renders the aggregated output of a `batch`/`run` pipeline: one numbered
+/// status line per step (`[3/12] click #submit → ✔`) followed by a final
+/// success/failure summary, or — in `--json` mode — a single top-level
+/// array of the individual `Response` objects in original step order.
+pub fn print_batch_results(results: &[crate::batch::BatchResult], json_mode: bool) {
+    if json_mode {
+        let responses: Vec<&Response> = results.iter().map(|r| &r.response).collect();
+        println!("{}", serde_json::to_string(&responses).unwrap_or_default());
+        return;
+    }
+
+    let total = results.len();
+    let mut failures = 0;
+    for result in results {
+        let indicator = if result.response.success {
+            color::success_indicator()
+        } else {
+            failures += 1;
+            color::error_indicator()
+        };
+        let command_line = if result.step.args.is_empty() {
+            result.step.command.clone()
+        } else {
+            format!("{} {}", result.step.command, result.step.args.join(" "))
+        };
+        println!(
+            "[{}/{}] {} \u{2192} {}",
+            result.step.index + 1,
+            total,
+            command_line,
+            indicator
+        );
+    }
+
+    let successes = total - failures;
+    if failures == 0 {
+        println!(
+            "{} {}/{} steps succeeded",
+            color::success_indicator(),
+            successes,
+            total
+        );
+    } else {
+        println!(
+            "{} {}/{} steps succeeded, {} failed",
+            color::warning_indicator(),
+            successes,
+            total,
+            failures
+        );
+    }
+}
+
+/// Renders a testplan's [`crate::testplan::PlanReport`]: one status line
+/// per step followed by a pass/fail summary, or the full JSON report when
+/// `json_mode` is set.
+pub fn print_plan_report(report: &crate::testplan::PlanReport, json_mode: bool) {
+    if json_mode {
+        println!("{}", serde_json::to_string(report).unwrap_or_default());
+        return;
+    }
+
+    println!("{}", color::bold(&report.name));
+    for (i, step) in report.steps.iter().enumerate() {
+        let indicator = if step.passed {
+            color::success_indicator()
+        } else {
+            color::error_indicator()
+        };
+        print!(
+            "[{}/{}] {} ({}ms) {}",
+            i + 1,
+            report.steps.len(),
+            step.command,
+            step.duration_ms,
+            indicator
+        );
+        if let Some(error) = &step.error {
+            println!(" - {}", error);
+        } else {
+            println!();
+        }
+    }
+
+    if report.passed {
+        println!("{} testplan passed", color::success_indicator());
+    } else {
+        println!("{} testplan failed", color::error_indicator());
+    }
+}
+
+/// Renders a completed [`crate::load::LoadReport`] summary: total
+/// requests/failures and p50/p90/p99 latency, or the full JSON report
+/// when `json_mode` is set.
+pub fn print_load_report(report: &crate::load::LoadReport, json_mode: bool) {
+    if json_mode {
+        println!("{}", serde_json::to_string(report).unwrap_or_default());
+        return;
+    }
+
+    println!(
+        "{} {} clients \u{b7} {}s \u{b7} {} requests \u{b7} {} failures",
+        color::bold("Load test"),
+        report.clients,
+        report.duration_s,
+        report.total_requests,
+        report.failures
+    );
+    println!(
+        "  p50 {}ms \u{b7} p90 {}ms \u{b7} p99 {}ms",
+        report.p50_ms, report.p90_ms, report.p99_ms
+    );
+}
 
 pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
+    print_response_opts(resp, json_mode, action, &PrintOptions::default())
+}
+
+/// Same as [`print_response`], with additional rendering knobs (see
+/// [`PrintOptions`]) for branches that need more than `resp`/`json_mode`/`action`.
+pub fn print_response_opts(
+    resp: &Response,
+    json_mode: bool,
+    action: Option<&str>,
+    opts: &PrintOptions,
+) {
+    print_response_body(resp, json_mode, action, opts);
+
+    // The --stats footer only needs to be printed explicitly in text mode;
+    // --json already serializes the whole `Response`, stats included.
+    if !json_mode && opts.show_stats {
+        if let Some(stats) = resp.data.as_ref().and_then(|d| d.get("stats")) {
+            print_stats_footer(stats);
+        }
+    }
+}
+
+fn print_response_body(resp: &Response, json_mode: bool, action: Option<&str>, opts: &PrintOptions) {
     if json_mode {
         println!("{}", serde_json::to_string(resp).unwrap_or_default());
         return;
@@ -27,6 +271,13 @@ pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
             println!("{}", url);
             return;
         }
+        // Accessibility tree (falls back to the plaintext snapshot below when absent)
+        if let Some(nodes) = data.get("accessibility").and_then(|v| v.as_array()) {
+            for node in nodes {
+                print_accessibility_node(node, 0);
+            }
+            return;
+        }
         // Snapshot
         if let Some(snapshot) = data.get("snapshot").and_then(|v| v.as_str()) {
             println!("{}", snapshot);
@@ -162,26 +413,49 @@ pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
         }
         // Cookies
         if let Some(cookies) = data.get("cookies").and_then(|v| v.as_array()) {
-            for cookie in cookies {
-                let name = cookie.get("name").and_then(|v| v.as_str()).unwrap_or("");
-                let value = cookie.get("value").and_then(|v| v.as_str()).unwrap_or("");
-                println!("{}={}", name, value);
+            match opts.cookie_format {
+                Some("netscape") => {
+                    println!("# Netscape HTTP Cookie File");
+                    for cookie in cookies {
+                        println!("{}", format_netscape_cookie_line(cookie));
+                    }
+                }
+                Some("json") => {
+                    println!("{}", serde_json::to_string_pretty(cookies).unwrap_or_default());
+                }
+                _ => {
+                    for cookie in cookies {
+                        let name = cookie.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                        let value = cookie.get("value").and_then(|v| v.as_str()).unwrap_or("");
+                        println!("{}={}", name, value);
+                    }
+                }
             }
             return;
         }
         // Network requests
         if let Some(requests) = data.get("requests").and_then(|v| v.as_array()) {
-            if requests.is_empty() {
-                println!("No requests captured");
-            } else {
-                for req in requests {
-                    let method = req.get("method").and_then(|v| v.as_str()).unwrap_or("GET");
-                    let url = req.get("url").and_then(|v| v.as_str()).unwrap_or("");
-                    let resource_type = req
-                        .get("resourceType")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("");
-                    println!("{} {} ({})", method, url, resource_type);
+            let mut printed = 0;
+            for req in requests {
+                let method = req.get("method").and_then(|v| v.as_str()).unwrap_or("GET");
+                let url = req.get("url").and_then(|v| v.as_str()).unwrap_or("");
+                let resource_type = req
+                    .get("resourceType")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                if let Some(filter) = opts.request_filter {
+                    if !filter.allows(url, resource_type) {
+                        continue;
+                    }
+                }
+                println!("{} {} ({})", method, url, resource_type);
+                printed += 1;
+            }
+            if printed == 0 {
+                if !requests.is_empty() && opts.request_filter.is_some() {
+                    println!("No requests matched the given filters");
+                } else {
+                    println!("No requests captured");
                 }
             }
             return;
@@ -310,18 +584,21 @@ pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
                     .or_else(|| data.get("filename"))
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
+                let fingerprint = artifact_annotation(path, opts.no_checksum);
                 if filename.is_empty() {
                     println!(
-                        "{} Downloaded to {}",
+                        "{} Downloaded to {}{}",
                         color::success_indicator(),
-                        color::green(path)
+                        color::green(path),
+                        fingerprint
                     );
                 } else {
                     println!(
-                        "{} Downloaded to {} ({})",
+                        "{} Downloaded to {} ({}){}",
                         color::success_indicator(),
                         color::green(path),
-                        filename
+                        filename,
+                        fingerprint
                     );
                 }
                 return;
@@ -329,41 +606,49 @@ pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
         }
         // Path-based operations (screenshot/pdf/trace/har/download/state/video)
         if let Some(path) = data.get("path").and_then(|v| v.as_str()) {
+            let fingerprint = artifact_annotation(path, opts.no_checksum);
             match action.unwrap_or("") {
                 "screenshot" => println!(
-                    "{} Screenshot saved to {}",
+                    "{} Screenshot saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "pdf" => println!(
-                    "{} PDF saved to {}",
+                    "{} PDF saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "trace_stop" => println!(
-                    "{} Trace saved to {}",
+                    "{} Trace saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "har_stop" => println!(
-                    "{} HAR saved to {}",
+                    "{} HAR saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "download" | "waitfordownload" => println!(
-                    "{} Download saved to {}",
+                    "{} Download saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "video_stop" => println!(
-                    "{} Video saved to {}",
+                    "{} Video saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "state_save" => println!(
-                    "{} State saved to {}",
+                    "{} State saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
                 "state_load" => {
                     if let Some(note) = data.get("note").and_then(|v| v.as_str()) {
@@ -383,9 +668,10 @@ pub fn print_response(resp: &Response, json_mode: bool, action: Option<&str>) {
                     println!("Path: {}", path);
                 }
                 _ => println!(
-                    "{} Saved to {}",
+                    "{} Saved to {}{}",
                     color::success_indicator(),
-                    color::green(path)
+                    color::green(path),
+                    fingerprint
                 ),
             }
             return;
@@ -530,14 +816,25 @@ Usage: stella-browser fill <selector> <text>
 Clears the input field and fills it with the specified text.
 This replaces any existing content in the field.
 
-Global Options:
-  --json               Output as JSON
-  --session <name>     Use specific session
+On iOS, keystrokes sent via fill can fail to reach a focused field with no
+visible effect. fill waits for the software keyboard to appear before typing,
+falling back to a native tap to refocus the field if it doesn't show up, and
+sends characters through the XCUITest keyboard endpoint rather than a JS
+value-set. --clear-native selects-all and deletes through the native
+keyboard instead of setting .value, since value-set bypasses input events
+that some pages depend on.
+
+Options:
+  --clear-native        Clear via native select-all/delete instead of .value (iOS)
+  --dismiss-keyboard    Dismiss the software keyboard after filling (iOS)
+  --json                Output as JSON
+  --session <name>      Use specific session
 
 Examples:
   stella-browser fill "#email" "user@example.com"
   stella-browser fill @e3 "Hello World"
   stella-browser fill "input[name='search']" "query"
+  stella-browser -p ios fill "#email" "user@example.com" --clear-native --dismiss-keyboard
 "##
         }
         "type" => {
@@ -549,13 +846,20 @@ Usage: stella-browser type <selector> <text>
 Types text into the specified element character by character.
 Unlike fill, this does not clear existing content first.
 
-Global Options:
-  --json               Output as JSON
-  --session <name>     Use specific session
+On iOS, type waits for the software keyboard to appear before sending
+keystrokes, falling back to a native tap to refocus the field if it doesn't
+show up, and sends characters through the XCUITest keyboard endpoint rather
+than a JS value-set.
+
+Options:
+  --dismiss-keyboard    Dismiss the software keyboard after typing (iOS)
+  --json                Output as JSON
+  --session <name>      Use specific session
 
 Examples:
   stella-browser type "#search" "hello"
   stella-browser type @e2 "additional text"
+  stella-browser -p ios type "#search" "hello" --dismiss-keyboard
 "##
         }
         "hover" => {
@@ -909,6 +1213,9 @@ Options:
   -c, --compact        Remove empty structural elements
   -d, --depth <n>      Limit tree depth
   -s, --selector <sel> Scope snapshot to CSS selector
+  -a, --accessibility  Render as an indented ARIA accessibility tree
+                       (role, accessible name, state flags) instead of
+                       plaintext, e.g. `button "Submit" [focused]`
 
 Global Options:
   --json               Output as JSON
@@ -920,6 +1227,7 @@ Examples:
   stella-browser snapshot -i -C         # Interactive + cursor-interactive elements
   stella-browser snapshot --compact --depth 5
   stella-browser snapshot -s "#main-content"
+  stella-browser snapshot --accessibility
 "##
         }
 
@@ -1058,6 +1366,7 @@ Locators:
   first <selector>         First matching element
   last <selector>          Last matching element
   nth <index> <selector>   Nth matching element (0-based)
+  xpath <expression>       Find by XPath expression
 
 Actions (default: click):
   click, fill, type, hover, focus, check, uncheck
@@ -1065,6 +1374,8 @@ Actions (default: click):
 Options:
   --name <name>        Filter role by accessible name
   --exact              Require exact text match
+  --all                With xpath, run the action on every matching node
+                       instead of just the first
 
 Global Options:
   --json               Output as JSON
@@ -1078,6 +1389,8 @@ Examples:
   stella-browser find testid "login-form" click
   stella-browser find first "li.item" click
   stella-browser find nth 2 ".card" hover
+  stella-browser find xpath "//div[@id='searchform']//input[@type='text']" fill "query"
+  stella-browser find xpath "//li[contains(@class,'item')]" click --all
 "##
         }
 
@@ -1128,6 +1441,22 @@ Settings:
   credentials <user> <pass>  Set HTTP authentication
   media [dark|light]         Set color scheme preference
         [reduced-motion]     Enable reduced motion
+  proxy <server> [options]   Route the context through a proxy (W3C `proxy`)
+    --bypass <list>          Comma-separated hosts to bypass the proxy for
+  pageload <strategy>        Default load strategy for open/goto (W3C `pageLoadStrategy`)
+                             load|normal, domcontentloaded|eager, none
+  insecure <on|off>          Accept invalid TLS certificates (W3C `acceptInsecureCerts`)
+  timeouts <options>         Default waits applied to every subsequent command
+    --navigation <ms>        Timeout for open/goto/back/forward/reload
+    --action <ms>            Timeout for click/fill/wait/etc.
+  dialog <policy> [text]     Default handler for alert/confirm/prompt dialogs
+                             (W3C `unhandledPromptBehavior`); see
+                             `stella-browser help dialog` for policies
+  useragent <string>         Set the User-Agent (or "chrome" for a desktop Chrome preset)
+  cache <on|off>             Toggle the HTTP cache
+  ajax-idle <ms>             How long `wait --load networkidle` tolerates
+                             in-flight XHR/fetch before considering the page settled
+  save-media <dir>           Persist media/attachment responses the page loads into a folder
 
 Global Options:
   --json               Output as JSON
@@ -1142,6 +1471,18 @@ Examples:
   stella-browser set credentials admin secret123
   stella-browser set media dark
   stella-browser set media light reduced-motion
+  stella-browser set proxy "http://127.0.0.1:7890" --bypass "localhost,*.internal.com"
+  stella-browser set pageload eager
+  stella-browser set insecure on
+  stella-browser set timeouts --navigation 30000 --action 5000
+  stella-browser set dialog accept
+  stella-browser set dialog accept "default prompt text"
+  stella-browser set dialog manual
+  stella-browser set useragent chrome
+  stella-browser set useragent "MyBot/1.0 (+https://example.com/bot)"
+  stella-browser set cache off
+  stella-browser set ajax-idle 500
+  stella-browser set save-media ./downloads
 "##
         }
 
@@ -1162,6 +1503,9 @@ Subcommands:
   requests [options]         List captured requests
     --clear                  Clear request log
     --filter <pattern>       Filter by URL pattern
+    --url-match <glob>       Only print requests matching this glob (repeatable)
+    --url-ignore <glob>      Never print requests matching this glob (repeatable)
+    --resource-type <type>   Only print requests of this resource type (repeatable)
 
 Global Options:
   --json               Output as JSON
@@ -1173,6 +1517,9 @@ Examples:
   stella-browser network unroute
   stella-browser network requests
   stella-browser network requests --filter "api"
+  stella-browser network requests --url-match 'https://api.example.com/**'
+  stella-browser network requests --url-ignore '**/*.{png,css,woff2}'
+  stella-browser network requests --resource-type xhr --resource-type fetch
   stella-browser network requests --clear
 "##
         }
@@ -1222,6 +1569,10 @@ Operations:
   set <name> <value> [options]       Set a cookie with optional properties
   clear                              Clear all cookies
 
+Get Options:
+  --cookie-format <netscape|json>    Render `get` as a cookies.txt jar or
+                                     a JSON array instead of name=value lines
+
 Cookie Set Options:
   --url <url>                        URL for the cookie (allows setting before page load)
   --domain <domain>                  Cookie domain (e.g., ".example.com")
@@ -1257,6 +1608,12 @@ Examples:
   # Get all cookies
   stella-browser cookies
 
+  # Export cookies as a Netscape cookies.txt jar for curl/reqwest
+  stella-browser cookies get --cookie-format netscape > cookies.txt
+
+  # Export cookies as a JSON array
+  stella-browser cookies get --cookie-format json
+
   # Clear all cookies
   stella-browser cookies clear
 "##
@@ -1313,6 +1670,40 @@ Examples:
 "##
         }
 
+        // === Context (iOS WEBVIEW/NATIVE_APP) ===
+        "context" => {
+            r##"
+stella-browser context - Switch between native and web automation contexts
+
+Usage: stella-browser context <list|name|web|native>
+
+iOS Safari automation frequently needs to drop from the web context into
+the native context (e.g., to dismiss system dialogs, fraud warnings, or
+handle native keyboard/file pickers) and then return. `snapshot` and
+`find` operate over whichever context is active. The active context is
+persisted per session, so an agent can switch to native, tap a system
+alert button, then switch back to web and resume DOM interaction.
+
+Requires the ios provider.
+
+Operations:
+  list             Enumerate available contexts (NATIVE_APP plus each WEBVIEW_* page)
+  <name>           Switch to a specific context by its raw WDA name
+  web              Switch to the (first) WEBVIEW_* context
+  native           Switch to NATIVE_APP
+
+Global Options:
+  --json               Output as JSON
+  --session <name>     Use specific session
+
+Examples:
+  stella-browser -p ios context list
+  stella-browser -p ios context native
+  stella-browser -p ios tap "Allow"      # tap a native system alert button
+  stella-browser -p ios context web
+"##
+        }
+
         // === Frame ===
         "frame" => {
             r##"
@@ -1346,10 +1737,22 @@ Usage: stella-browser dialog <response> [text]
 
 Respond to browser dialogs (alert, confirm, prompt).
 
+By default `dialog` is reactive: it answers whichever dialog is currently
+open, which races against page JavaScript. For unattended scripts, install
+a persistent default handler instead with `set dialog <policy> [text]`
+(see below) so dialogs are auto-answered the moment they open.
+
 Operations:
   accept [text]        Accept dialog, optionally with prompt text
   dismiss              Dismiss/cancel dialog
 
+Default Policy (via `set dialog`):
+  accept [text]         Auto-accept every dialog, optionally with prompt text
+  dismiss               Auto-dismiss every dialog
+  accept-notify [text]  Auto-accept, but still print a console notification
+  dismiss-notify        Auto-dismiss, but still print a console notification
+  manual                Restore the default wait-for-explicit-response behavior
+
 Global Options:
   --json               Output as JSON
   --session <name>     Use specific session
@@ -1358,6 +1761,10 @@ Examples:
   stella-browser dialog accept
   stella-browser dialog accept "my input"
   stella-browser dialog dismiss
+
+  # Survive unexpected dialogs in an unattended script
+  stella-browser set dialog accept-notify
+  stella-browser set dialog manual   # back to explicit per-dialog handling
 "##
         }
 
@@ -1542,6 +1949,120 @@ Examples:
 "##
         }
 
+        // === Run (testplan) ===
+        "run" => {
+            r##"
+stella-browser run - Run a declarative testplan
+
+Usage: stella-browser run <plan.yaml> [options]
+
+Executes a named sequence of the existing verbs (open, click, fill, wait,
+eval, get, …) from a YAML testplan. Each step may set `capture: name` to
+store its `get`/`eval` result into a plan-scoped variable, and any
+argument may reference `${name}` for substitution before execution
+(including built-ins like `${HOSTNAME}`, overridable with --var).
+
+Variables resolve in this order: CLI-provided globals (--var), then
+plan-level globals, then step captures. An unresolved `${var}` aborts the
+run with an error.
+
+Steps may carry an `assert:` block (e.g. `text_present`, `text_absent`,
+status_lt, status_eq) that marks the step failed if unmet. The run exits
+non-zero if any step fails.
+
+Plan format:
+  name: login-flow
+  globals:
+    BASE_URL: https://app.example.com
+  steps:
+    - command: open
+      args: ["${BASE_URL}/login"]
+    - command: fill
+      args: ["#email", "user@example.com"]
+    - command: get
+      args: ["text", "#welcome"]
+      capture: welcome_text
+      assert:
+        text_present: "Welcome"
+
+Options:
+  --var <name>=<value>  Set a CLI-level global (repeatable, overrides plan globals)
+
+Global Options:
+  --json               Output the full structured report (steps, pass/fail, timings)
+  --session <name>     Use specific session
+
+Examples:
+  stella-browser run ./login.yaml
+  stella-browser run ./login.yaml --var BASE_URL=https://staging.example.com
+  stella-browser run ./login.yaml --json
+"##
+        }
+
+        // === Load (parallel load test) ===
+        "load" => {
+            r##"
+stella-browser load - Replay a testplan across concurrent sessions
+
+Usage: stella-browser load <plan.yaml> --clients <N> --duration <s> [options]
+
+Spins up N isolated browser sessions (see the `session` subsystem), each
+repeatedly replaying the testplan's step list until the duration elapses.
+Collects per-request timing and outcome metrics and prints aggregate
+stats: total requests, failures, and latency percentiles (p50/p90/p99).
+
+Requests with a response status >= 400 count as failures unless the
+step carries its own `assert:` block, matching stella's load-engine
+semantics where failures and timeouts both count toward the failure
+total.
+
+Options:
+  --clients <N>        Number of concurrent sessions (required)
+  --duration <s>       How long to run, in seconds (required)
+  --ramp <s>           Stagger client startup over this many seconds (default: 0)
+
+Global Options:
+  --json               Output the full structured report for CI ingestion
+
+Examples:
+  stella-browser load ./login.yaml --clients 20 --duration 60
+  stella-browser load ./login.yaml --clients 50 --duration 120 --ramp 10 --json
+"##
+        }
+
+        // === Batch ===
+        "batch" => {
+            r##"
+stella-browser batch - Run a sequence of commands as a single pipeline
+
+Usage: stella-browser batch [script]
+
+Reads a newline- or JSON-delimited list of commands from stdin (or the
+given script file) and executes them against a single session. Read-only
+steps (snapshot, get, is, screenshot, console, errors, tab list) run
+concurrently, bounded by --concurrency; every other step, eval included, is
+mutating and runs alone, in order.
+
+Each step prints a numbered status line, in step order, followed by a
+final summary. In --json mode, the full array of step Response objects is
+printed instead.
+
+Options:
+  --concurrency <n>    Max read-only steps to run in parallel (default: 4)
+
+Global Options:
+  --json               Output as JSON
+  --session <name>     Use specific session
+
+Examples:
+  stella-browser batch ./steps.txt
+  echo 'open example.com
+  click #submit
+  snapshot' | stella-browser batch
+  stella-browser batch ./steps.txt --concurrency 8 --json
+"##
+        }
+
         // === Install ===
         "install" => {
             r##"
@@ -1610,14 +2131,27 @@ Usage: stella-browser tap <selector>
 Taps an element. This is an alias for 'click' that provides semantic clarity
 for touch-based interfaces like iOS Safari.
 
+On some pages, element-level clicks in iOS Safari silently no-op (the
+command succeeds but nothing happens). --native resolves the element's
+bounding box, converts it to native screen coordinates using the current
+visualViewport scale/offset and device scale factor, then issues a native
+touch through the XCUITest touch-action API instead of a WebView click.
+On failure of the normal WebView tap path, tap automatically retries once
+in native mode even without the flag.
+
 Options:
-  --json               Output as JSON
-  --session <name>     Use specific session
+  --native              Use native touch-action tap instead of a WebView click
+  --json                Output as JSON
+  --session <name>      Use specific session
+
+Environment:
+  STELLA_BROWSER_IOS_NATIVE_TAP   Set to "1" to make --native the default for this session
 
 Examples:
   stella-browser tap "#submit-button"
   stella-browser tap @e1
   stella-browser -p ios tap "button:has-text('Sign In')"
+  stella-browser -p ios tap @e1 --native
 "##
         }
         "swipe" => {
@@ -1645,12 +2179,30 @@ Examples:
         }
         "device" => {
             r##"
-stella-browser device - Manage iOS simulators
+stella-browser device - Manage iOS simulators and real devices
 
 Usage: stella-browser device <subcommand>
 
 Subcommands:
-  list    List available iOS simulators
+  list                      List available iOS simulators
+  list --real               List connected physical devices via go-ios (UDID,
+                            name, product version), for driving with --udid over USB
+  lock [seconds]            Lock the screen, optionally auto-unlocking after N seconds
+  unlock                    Unlock the screen
+  is-locked                 Print whether the screen is currently locked
+  background [seconds]      Home-button the Safari session, optionally
+                            restoring it to the foreground after N seconds
+  clipboard get             Print the primary pasteboard's text content
+  clipboard set <text>      Set the primary pasteboard's text content
+  orientation <portrait|landscape>   Rotate the device
+
+Each of lock/unlock/is-locked/background/clipboard/orientation maps to the
+corresponding WDA/XCUITest session endpoint and requires the ios provider.
+
+Real devices (iOS 17+) require go-ios (danielpaulus/go-ios) installed and,
+on first use, starting its tunnel daemon with elevated privileges
+(`ios tunnel start`; on Windows, wintun.dll must be in system32).
+WebDriverAgent is installed/launched automatically via `ios runwda`.
 
 Options:
   --json               Output as JSON
@@ -1658,7 +2210,15 @@ Options:
 
 Examples:
   stella-browser device list
+  stella-browser device list --real
   stella-browser -p ios device list
+  stella-browser -p ios --udid 00008030-001A2B3C4D5E open example.com
+  stella-browser -p ios device lock 5
+  stella-browser -p ios device is-locked
+  stella-browser -p ios device background 10
+  stella-browser -p ios device clipboard set "pasted text"
+  stella-browser -p ios device clipboard get
+  stella-browser -p ios device orientation landscape
 "##
         }
 
@@ -1712,7 +2272,7 @@ Check State:  stella-browser is <what> <selector>
   visible, enabled, checked
 
 Find Elements:  stella-browser find <locator> <value> <action> [text]
-  role, text, label, placeholder, alt, title, testid, first, last, nth
+  role, text, label, placeholder, alt, title, testid, first, last, nth, xpath
 
 Mouse:  stella-browser mouse <action> [args]
   move <x> <y>, down [btn], up [btn], wheel <dy> [dx]
@@ -1721,6 +2281,9 @@ Browser Settings:  stella-browser set <setting> [value]
   viewport <w> <h>, device <name>, geo <lat> <lng>
   offline [on|off], headers <json>, credentials <user> <pass>
   media [dark|light] [reduced-motion]
+  proxy <server> [--bypass <list>], pageload <strategy>
+  insecure <on|off>, timeouts [--navigation <ms>] [--action <ms>]
+  useragent <string|chrome>, cache <on|off>, ajax-idle <ms>, save-media <dir>
 
 Network:  stella-browser network <action>
   route <url> [--abort|--body <json>]
@@ -1728,12 +2291,16 @@ Network:  stella-browser network <action>
   requests [--clear] [--filter <pattern>]
 
 Storage:
-  cookies [get|set|clear]    Manage cookies (set supports --url, --domain, --path, --httpOnly, --secure, --sameSite, --expires)
+  cookies [get|set|clear]    Manage cookies (get supports --cookie-format netscape|json;
+                             set supports --url, --domain, --path, --httpOnly, --secure, --sameSite, --expires)
   storage <local|session>    Manage web storage
 
 Tabs:
   tab [new|list|close|<n>]   Manage tabs
 
+iOS Context:
+  context [list|<name>|web|native]   Switch between native and WEBVIEW automation contexts
+
 Debug:
   trace start|stop [path]    Record trace
   record start <path> [url]  Start video recording (WebM)
@@ -1746,6 +2313,15 @@ Sessions:
   session                    Show current session name
   session list               List active sessions
 
+Testplans:
+  run <plan.yaml>            Run a declarative testplan (capture/${vars}/asserts)
+  load <plan.yaml>           Replay a testplan across concurrent sessions
+                             (--clients <n> --duration <s> [--ramp <s>])
+
+Batch:
+  batch [script]             Run commands from stdin/a script as one pipeline
+                             (--concurrency <n> for parallel read-only steps)
+
 Setup:
   install                    Install browser binaries
   install --with-deps        Also install system dependencies (Linux)
@@ -1773,9 +2349,13 @@ Options:
   --ignore-https-errors      Ignore HTTPS certificate errors
   --allow-file-access        Allow file:// URLs to access local files (Chromium only)
   -p, --provider <name>      Browser provider: ios, browserbase, kernel, browseruse
-  --device <name>            iOS device name (e.g., "iPhone 15 Pro")
+  --device <name>            iOS simulator device name (e.g., "iPhone 15 Pro")
+  --udid <udid>              Real iOS device UDID (or STELLA_BROWSER_IOS_UDID), drives
+                             the device over USB via go-ios + WebDriverAgent
   --json                     JSON output
   --full, -f                 Full page screenshot
+  --no-checksum              Skip SHA-256 hashing of saved artifacts (useful for large videos)
+  --stats                    Print a timing/transfer footer after the command's output
   --headed                   Show browser window (not headless)
   --cdp <port>               Connect via CDP (Chrome DevTools Protocol)
   --debug                    Debug output
@@ -1798,14 +2378,20 @@ Examples:
   stella-browser get text @e1
   stella-browser screenshot --full
   stella-browser --cdp 9222 snapshot      # Connect via CDP port
+  stella-browser open example.com --stats # Show timing/transfer footer
   stella-browser --profile ~/.myapp open example.com  # Persistent profile
 
 iOS Simulator (requires Xcode and Appium):
   stella-browser -p ios open example.com                    # Use default iPhone
   stella-browser -p ios --device "iPhone 15 Pro" open url   # Specific device
   stella-browser -p ios device list                         # List simulators
+  stella-browser -p ios device list --real                  # List physical devices
+  stella-browser -p ios --udid 00008030-001A2B3C4D5E open example.com  # Real device over USB
   stella-browser -p ios swipe up                            # Swipe gesture
   stella-browser -p ios tap @e1                             # Touch element
+  stella-browser -p ios context native                      # Drop to native context
+  stella-browser -p ios tap "Allow"                          # Tap a system alert button
+  stella-browser -p ios context web                          # Back to the DOM
 "#
     );
 }
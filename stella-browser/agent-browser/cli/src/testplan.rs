@@ -0,0 +1,233 @@
+//! Declarative testplan runner (`stella-browser run <plan.yaml>`): executes
+//! a named sequence of CLI verbs as a single scripted run, with
+//! plan-scoped variable capture/substitution and per-step assertions.
+
+use crate::batch::Executor;
+use crate::connection::Response;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// A declarative testplan: a named sequence of steps plus plan-scoped
+/// global variables, modeled on stella's testplan/usecase concept.
+#[derive(Deserialize, Clone)]
+pub struct TestPlan {
+    pub name: String,
+    #[serde(default)]
+    pub globals: HashMap<String, String>,
+    pub steps: Vec<PlanStep>,
+}
+
+/// One step of a testplan: a command plus its arguments, an optional name
+/// to capture its result under, and an optional assertion block.
+#[derive(Deserialize, Clone)]
+pub struct PlanStep {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub capture: Option<String>,
+    #[serde(default)]
+    pub assert: Option<Assertions>,
+}
+
+/// Assertions checkable against a step's `Response`.
+#[derive(Deserialize, Clone, Default)]
+pub struct Assertions {
+    pub text_present: Option<String>,
+    pub text_absent: Option<String>,
+    pub status_lt: Option<i64>,
+    pub status_eq: Option<i64>,
+}
+
+impl Assertions {
+    /// Checks every configured assertion against `response`, returning the
+    /// first failure description (if any).
+    pub(crate) fn check(&self, response: &Response) -> Option<String> {
+        let text = response
+            .data
+            .as_ref()
+            .and_then(|d| d.get("text").or_else(|| d.get("snapshot")))
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let status = response
+            .data
+            .as_ref()
+            .and_then(|d| d.get("status"))
+            .and_then(|v| v.as_i64());
+
+        if let Some(expected) = &self.text_present {
+            if !text.contains(expected.as_str()) {
+                return Some(format!("expected text to contain {:?}", expected));
+            }
+        }
+        if let Some(unexpected) = &self.text_absent {
+            if text.contains(unexpected.as_str()) {
+                return Some(format!("expected text to not contain {:?}", unexpected));
+            }
+        }
+        if let (Some(max), Some(status)) = (self.status_lt, status) {
+            if status >= max {
+                return Some(format!("expected status < {}, got {}", max, status));
+            }
+        }
+        if let (Some(expected), Some(status)) = (self.status_eq, status) {
+            if status != expected {
+                return Some(format!("expected status == {}, got {}", expected, status));
+            }
+        }
+        None
+    }
+}
+
+/// Parses a testplan from YAML source.
+pub fn parse_plan(yaml: &str) -> Result<TestPlan, String> {
+    serde_yaml::from_str(yaml).map_err(|e| e.to_string())
+}
+
+/// Resolves `${name}` references against three layers, checked in this
+/// order: CLI-provided globals, then plan-level globals, then step
+/// captures — mirroring stella's rule that the client/global container is
+/// checked before usecase resources.
+pub struct VarTable {
+    cli_globals: HashMap<String, String>,
+    plan_globals: HashMap<String, String>,
+    captures: HashMap<String, String>,
+}
+
+impl VarTable {
+    pub fn new(mut cli_globals: HashMap<String, String>, plan_globals: HashMap<String, String>) -> Self {
+        cli_globals
+            .entry("HOSTNAME".to_string())
+            .or_insert_with(|| std::env::var("HOSTNAME").unwrap_or_else(|_| "localhost".to_string()));
+        Self {
+            cli_globals,
+            plan_globals,
+            captures: HashMap::new(),
+        }
+    }
+
+    pub fn capture(&mut self, name: String, value: String) {
+        self.captures.insert(name, value);
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.cli_globals
+            .get(name)
+            .or_else(|| self.plan_globals.get(name))
+            .or_else(|| self.captures.get(name))
+            .map(String::as_str)
+    }
+
+    /// Substitutes every `${name}` in `input`. Unresolved references abort
+    /// with a clear error rather than passing the literal text through.
+    pub fn resolve(&self, input: &str) -> Result<String, String> {
+        let mut out = String::with_capacity(input.len());
+        let mut rest = input;
+        while let Some(start) = rest.find("${") {
+            let Some(end) = rest[start..].find('}').map(|i| i + start) else {
+                out.push_str(rest);
+                return Ok(out);
+            };
+            out.push_str(&rest[..start]);
+            let name = &rest[start + 2..end];
+            match self.get(name) {
+                Some(value) => out.push_str(value),
+                None => return Err(format!("unresolved variable reference: ${{{}}}", name)),
+            }
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+}
+
+/// Outcome of one executed step, as reported in the JSON test report.
+#[derive(serde::Serialize)]
+pub struct StepReport {
+    pub command: String,
+    pub passed: bool,
+    pub duration_ms: u128,
+    pub error: Option<String>,
+}
+
+/// Final report for a `run` invocation: exits non-zero if `passed` is false.
+#[derive(serde::Serialize)]
+pub struct PlanReport {
+    pub name: String,
+    pub passed: bool,
+    pub steps: Vec<StepReport>,
+}
+
+/// Runs `plan` step by step through `execute`, resolving `${var}`
+/// references before each step, capturing results into the variable
+/// table, and checking any configured assertions. Stops at the first
+/// failing (or unresolved) step.
+pub async fn run_plan(plan: &TestPlan, cli_globals: HashMap<String, String>, execute: Executor) -> PlanReport {
+    let mut vars = VarTable::new(cli_globals, plan.globals.clone());
+    let mut steps = Vec::with_capacity(plan.steps.len());
+    let mut overall_passed = true;
+
+    for step in &plan.steps {
+        let started = Instant::now();
+
+        let resolved_args = match step.args.iter().map(|a| vars.resolve(a)).collect::<Result<Vec<_>, _>>() {
+            Ok(args) => args,
+            Err(err) => {
+                steps.push(StepReport {
+                    command: step.command.clone(),
+                    passed: false,
+                    duration_ms: started.elapsed().as_millis(),
+                    error: Some(err),
+                });
+                overall_passed = false;
+                break;
+            }
+        };
+
+        let response = execute(step.command.clone(), resolved_args).await;
+
+        if let Some(name) = &step.capture {
+            if let Some(value) = capture_value(&response) {
+                vars.capture(name.clone(), value);
+            }
+        }
+
+        let error = if !response.success {
+            response.error.clone()
+        } else {
+            step.assert.as_ref().and_then(|a| a.check(&response))
+        };
+        let passed = error.is_none();
+        overall_passed &= passed;
+
+        steps.push(StepReport {
+            command: step.command.clone(),
+            passed,
+            duration_ms: started.elapsed().as_millis(),
+            error,
+        });
+
+        if !passed {
+            break;
+        }
+    }
+
+    PlanReport {
+        name: plan.name.clone(),
+        passed: overall_passed,
+        steps,
+    }
+}
+
+/// Extracts the capturable scalar from a step's response (text/value/etc).
+fn capture_value(response: &Response) -> Option<String> {
+    let data = response.data.as_ref()?;
+    for key in ["text", "value", "title", "url"] {
+        if let Some(v) = data.get(key).and_then(|v| v.as_str()) {
+            return Some(v.to_string());
+        }
+    }
+    data.get("result")
+        .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+}
@@ -0,0 +1,150 @@
+//! Small XPath subset evaluator backing `find xpath <expression>`. WDA/CDP
+//! locator strategies don't give us a native XPath engine to delegate to,
+//! so this walks a generic element tree matching absolute descendant paths
+//! (`//tag[predicate]`) directly — the subset `find xpath`'s help text and
+//! examples actually use, not the full XPath grammar.
+
+use std::collections::HashMap;
+
+/// A generic element node as reported by the page (tag name, attributes,
+/// and children), independent of whether the backend serializes it from a
+/// DOM or an accessibility tree.
+#[derive(Clone)]
+pub struct ElementNode {
+    pub tag: String,
+    pub attrs: HashMap<String, String>,
+    pub children: Vec<ElementNode>,
+}
+
+/// One `[...]` predicate attached to a path step.
+#[derive(Clone)]
+enum Predicate {
+    AttrEquals(String, String),
+    AttrContains(String, String),
+}
+
+impl Predicate {
+    fn matches(&self, node: &ElementNode) -> bool {
+        match self {
+            Predicate::AttrEquals(attr, value) => {
+                node.attrs.get(attr).is_some_and(|v| v == value)
+            }
+            Predicate::AttrContains(attr, value) => node
+                .attrs
+                .get(attr)
+                .is_some_and(|v| v.contains(value.as_str())),
+        }
+    }
+}
+
+/// One `//tag[predicate]` step of a parsed expression. Only the
+/// descendant axis (`//`) is supported, matching every example in the
+/// `find xpath` help text.
+struct Step {
+    tag: Option<String>,
+    predicates: Vec<Predicate>,
+}
+
+impl Step {
+    fn matches(&self, node: &ElementNode) -> bool {
+        self.tag
+            .as_deref()
+            .map(|tag| tag == node.tag)
+            .unwrap_or(true)
+            && self.predicates.iter().all(|p| p.matches(node))
+    }
+}
+
+/// Parses a `//`-separated expression into its steps. Supports a bare tag
+/// name, `*` (any tag), and `[@attr='value']` / `[contains(@attr,'value')]`
+/// predicates.
+fn parse(expression: &str) -> Result<Vec<Step>, String> {
+    let expression = expression.trim();
+    if !expression.starts_with("//") {
+        return Err(format!(
+            "unsupported xpath expression (must start with `//`): {expression}"
+        ));
+    }
+
+    expression
+        .split("//")
+        .filter(|step| !step.is_empty())
+        .map(parse_step)
+        .collect()
+}
+
+fn parse_step(step: &str) -> Result<Step, String> {
+    let tag_end = step.find('[').unwrap_or(step.len());
+    let tag_part = &step[..tag_end];
+    let tag = (tag_part != "*" && !tag_part.is_empty()).then(|| tag_part.to_string());
+
+    let mut predicates = Vec::new();
+    let mut rest = &step[tag_end..];
+    while let Some(start) = rest.find('[') {
+        let end = rest[start..]
+            .find(']')
+            .map(|i| i + start)
+            .ok_or_else(|| format!("unterminated predicate in xpath step: {step}"))?;
+        predicates.push(parse_predicate(&rest[start + 1..end])?);
+        rest = &rest[end + 1..];
+    }
+
+    Ok(Step { tag, predicates })
+}
+
+fn parse_predicate(predicate: &str) -> Result<Predicate, String> {
+    let predicate = predicate.trim();
+    if let Some(inner) = predicate
+        .strip_prefix("contains(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        let (attr, value) = inner
+            .split_once(',')
+            .map(|(a, b)| (a.trim(), b.trim()))
+            .ok_or_else(|| format!("expected `contains(@attr, 'value')`, got: contains({inner})"))?;
+        return Ok(Predicate::AttrContains(strip_at(attr), unquote(value)));
+    }
+    if let Some((attr, value)) = predicate.split_once('=') {
+        return Ok(Predicate::AttrEquals(
+            strip_at(attr.trim()),
+            unquote(value.trim()),
+        ));
+    }
+    Err(format!("unsupported xpath predicate: [{predicate}]"))
+}
+
+fn strip_at(attr: &str) -> String {
+    attr.trim_start_matches('@').to_string()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches(|c| c == '\'' || c == '"').to_string()
+}
+
+/// Collects every descendant of `node` (not including `node` itself)
+/// matching `step`.
+fn matching_descendants<'a>(node: &'a ElementNode, step: &Step) -> Vec<&'a ElementNode> {
+    let mut matches = Vec::new();
+    for child in &node.children {
+        if step.matches(child) {
+            matches.push(child);
+        }
+        matches.extend(matching_descendants(child, step));
+    }
+    matches
+}
+
+/// Evaluates `expression` against `root`, returning every node matching the
+/// full descendant path. `find xpath <expr> <action>` acts on the first
+/// result; `find xpath <expr> <action> --all` runs it on every result.
+pub fn evaluate<'a>(expression: &str, root: &'a ElementNode) -> Result<Vec<&'a ElementNode>, String> {
+    let steps = parse(expression)?;
+    let mut candidates = vec![root];
+    for step in &steps {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|candidate| matching_descendants(candidate, step))
+            .collect();
+    }
+    Ok(candidates)
+}